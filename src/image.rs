@@ -1,9 +1,11 @@
 use crate::header::VTFHeader;
 use crate::utils::get_offset;
 use crate::Error;
-use image::{DynamicImage, ImageBuffer, Pixel};
+use image::error::{DecodingError, ImageFormatHint};
+use image::{ColorType, DynamicImage, ImageBuffer, ImageDecoder, ImageError, ImageResult, Pixel};
 use num_enum::TryFromPrimitive;
 use parse_display::Display;
+use std::io::Cursor;
 use std::ops::Deref;
 use std::vec::Vec;
 use texpresso::Format;
@@ -38,28 +40,51 @@ impl<'a> VTFImage<'a> {
     }
 
     pub fn get_frame(&self, frame: u32) -> Result<&[u8], Error> {
-        let frame_size = self
-            .format
-            .frame_size(self.width as u32, self.height as u32)? as usize;
-        let base: usize =
-            self.offset + get_offset(&self.header, &self.format, frame, 0, 0, 0)? as usize;
+        self.get_mipmap(frame, 0)
+    }
+
+    /// Returns the number of mipmap levels stored for this image, from level 0 (full size) down
+    /// to the smallest level.
+    ///
+    /// This clamps the header's raw `mipmap_count` byte to what `width`/`height` can actually
+    /// support: a corrupted or malicious VTF can claim far more levels than its dimensions allow,
+    /// which would otherwise drive [`mipmap_dimensions`] to shift by 32 or more and panic.
+    pub fn mipmap_count(&self) -> u32 {
+        clamp_mipmap_count(self.header.mipmap_count as u32, self.width, self.height)
+    }
+
+    /// Computes the pixel dimensions of `mipmap`, halving the base dimensions once per level
+    /// down to a minimum of 1x1.
+    fn mipmap_dimensions(&self, mipmap: u32) -> (u32, u32) {
+        mipmap_dimensions(self.width, self.height, mipmap)
+    }
+
+    pub fn get_mipmap(&self, frame: u32, mipmap: u32) -> Result<&[u8], Error> {
+        validate_mipmap(mipmap, self.mipmap_count())?;
+        let (width, height) = self.mipmap_dimensions(mipmap);
+        let frame_size = self.format.frame_size(width, height)? as usize;
+        let base: usize = self.offset
+            + get_offset(&self.header, &self.format, frame, mipmap, 0, 0)? as usize;
         Ok(&self.bytes[base..base + frame_size])
     }
 
-    fn decode_dxt(&self, bytes: &[u8], variant: Format) -> Result<Vec<u8>, Error> {
-        let mut output: Vec<u8> = vec![0; self.width as usize * self.height as usize * 4];
-        variant.decompress(
-            bytes,
-            self.width as usize,
-            self.height as usize,
-            &mut output,
-        );
+    fn decode_dxt(
+        &self,
+        bytes: &[u8],
+        variant: Format,
+        width: u32,
+        height: u32,
+    ) -> Result<Vec<u8>, Error> {
+        let mut output: Vec<u8> = vec![0; width as usize * height as usize * 4];
+        variant.decompress(bytes, width as usize, height as usize, &mut output);
         Ok(output)
     }
 
     fn image_from_buffer<P, Container, F>(
         &self,
         buffer: Container,
+        width: u32,
+        height: u32,
         format: F,
     ) -> Result<DynamicImage, Error>
     where
@@ -68,49 +93,259 @@ impl<'a> VTFImage<'a> {
         Container: Deref<Target = [P::Subpixel]>,
         F: FnOnce(ImageBuffer<P, Container>) -> DynamicImage,
     {
-        ImageBuffer::from_raw(self.width as u32, self.height as u32, buffer)
+        ImageBuffer::from_raw(width, height, buffer)
             .map(format)
             .ok_or(Error::InvalidImageData)
     }
 
+    /// Encodes a `DynamicImage` into the raw pixel payload for `format`, mirroring
+    /// [`decode`](VTFImage::decode).
+    ///
+    /// This returns the frame's pixel bytes only, not a full `.vtf` file — `VTFHeader` is
+    /// currently only ever produced by parsing an existing VTF, so there is no way to build one
+    /// for a brand-new image through this crate yet. Pair these bytes with a hand-built header
+    /// (or a future `VTFHeader` constructor) to assemble a loadable file.
+    pub fn encode(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, Error> {
+        let width = image.width() as usize;
+        let height = image.height() as usize;
+        match format {
+            ImageFormat::Dxt1 | ImageFormat::Dxt1Onebitalpha => {
+                Self::encode_dxt(image, Format::Bc1, width, height)
+            }
+            ImageFormat::Dxt3 => Self::encode_dxt(image, Format::Bc2, width, height),
+            ImageFormat::Dxt5 => Self::encode_dxt(image, Format::Bc3, width, height),
+            ImageFormat::Rgba8888 => Ok(image.to_rgba8().into_raw()),
+            ImageFormat::Rgb888 => Ok(image.to_rgb8().into_raw()),
+            ImageFormat::Bgr888 => {
+                let mut rgb = image.to_rgb8().into_raw();
+                swap_red_blue(&mut rgb, 3);
+                Ok(rgb)
+            }
+            ImageFormat::Bgra8888 => {
+                let mut rgba = image.to_rgba8().into_raw();
+                convert_bgra(&mut rgba);
+                Ok(rgba)
+            }
+            _ => Err(Error::UnsupportedImageFormat(format)),
+        }
+    }
+
+    fn encode_dxt(
+        image: &DynamicImage,
+        variant: Format,
+        width: usize,
+        height: usize,
+    ) -> Result<Vec<u8>, Error> {
+        let rgba = image.to_rgba8().into_raw();
+        let mut output = vec![0; variant.compressed_size(width, height)];
+        variant.compress(&rgba, width, height, texpresso::Params::default(), &mut output);
+        Ok(output)
+    }
+
     pub fn decode(&self, frame: u32) -> Result<DynamicImage, Error> {
         let bytes = self.get_frame(frame)?;
+        self.decode_bytes(bytes, self.width as u32, self.height as u32)
+    }
+
+    /// Decodes a single mipmap level of `frame`. Level 0 is the full-size image; each
+    /// subsequent level halves both dimensions down to a minimum of 1x1.
+    pub fn decode_mipmap(&self, frame: u32, mipmap: u32) -> Result<DynamicImage, Error> {
+        validate_mipmap(mipmap, self.mipmap_count())?;
+        let (width, height) = self.mipmap_dimensions(mipmap);
+        let bytes = self.get_mipmap(frame, mipmap)?;
+        self.decode_bytes(bytes, width, height)
+    }
+
+    /// Decodes every mipmap level of `frame`, from level 0 down to the smallest level.
+    pub fn mipmaps(&self, frame: u32) -> impl Iterator<Item = Result<DynamicImage, Error>> + '_ {
+        (0..self.mipmap_count()).map(move |mipmap| self.decode_mipmap(frame, mipmap))
+    }
+
+    fn decode_bytes(&self, bytes: &[u8], width: u32, height: u32) -> Result<DynamicImage, Error> {
         match self.format {
             ImageFormat::Dxt1 => {
-                let buf = self.decode_dxt(bytes, Format::Bc1)?;
-                self.image_from_buffer(buf, DynamicImage::ImageRgba8)
+                let buf = self.decode_dxt(bytes, Format::Bc1, width, height)?;
+                self.image_from_buffer(buf, width, height, DynamicImage::ImageRgba8)
             }
             ImageFormat::Dxt1Onebitalpha => {
-                let buf = self.decode_dxt(bytes, Format::Bc1)?;
-                self.image_from_buffer(buf, DynamicImage::ImageRgba8)
+                let buf = self.decode_dxt(bytes, Format::Bc1, width, height)?;
+                self.image_from_buffer(buf, width, height, DynamicImage::ImageRgba8)
             }
             ImageFormat::Dxt3 => {
-                let buf = self.decode_dxt(bytes, Format::Bc2)?;
-                self.image_from_buffer(buf, DynamicImage::ImageRgba8)
+                let buf = self.decode_dxt(bytes, Format::Bc2, width, height)?;
+                self.image_from_buffer(buf, width, height, DynamicImage::ImageRgba8)
             }
             ImageFormat::Dxt5 => {
-                let buf = self.decode_dxt(bytes, Format::Bc3)?;
-                self.image_from_buffer(buf, DynamicImage::ImageRgba8)
+                let buf = self.decode_dxt(bytes, Format::Bc3, width, height)?;
+                self.image_from_buffer(buf, width, height, DynamicImage::ImageRgba8)
             }
             ImageFormat::Rgba8888 => {
-                self.image_from_buffer(bytes.to_vec(), DynamicImage::ImageRgba8)
+                self.image_from_buffer(bytes.to_vec(), width, height, DynamicImage::ImageRgba8)
+            }
+            ImageFormat::Rgb888 => {
+                self.image_from_buffer(bytes.to_vec(), width, height, DynamicImage::ImageRgb8)
             }
-            ImageFormat::Rgb888 => self.image_from_buffer(bytes.to_vec(), DynamicImage::ImageRgb8),
             ImageFormat::Bgr888 => {
                 let mut bgra = bytes.to_vec();
                 convert_bgra(&mut bgra);
-                self.image_from_buffer(bgra, DynamicImage::ImageRgb8)
+                self.image_from_buffer(bgra, width, height, DynamicImage::ImageRgb8)
             }
             ImageFormat::Bgra8888 => {
                 let mut bgra = bytes.to_vec();
                 convert_bgra(&mut bgra);
-                self.image_from_buffer(bgra, DynamicImage::ImageRgb8)
+                self.image_from_buffer(bgra, width, height, DynamicImage::ImageRgba8)
+            }
+            ImageFormat::Abgr8888 => self.image_from_buffer(
+                decode_packed::<Abgr8888>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Argb8888 => self.image_from_buffer(
+                decode_packed::<Argb8888>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Rgb565 => self.image_from_buffer(
+                decode_packed::<Rgb565>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Bgr565 => self.image_from_buffer(
+                decode_packed::<Bgr565>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Bgrx8888 => self.image_from_buffer(
+                decode_packed::<Bgrx8888>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Bgrx5551 => self.image_from_buffer(
+                decode_packed::<Bgrx5551>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Bgra5551 => self.image_from_buffer(
+                decode_packed::<Bgra5551>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Bgra4444 => self.image_from_buffer(
+                decode_packed::<Bgra4444>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::I8 => self.image_from_buffer(
+                decode_packed::<I8>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Ia88 => self.image_from_buffer(
+                decode_packed::<Ia88>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::A8 => self.image_from_buffer(
+                decode_packed::<A8>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::P8 => self.image_from_buffer(
+                decode_packed::<P8>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Uv88 => self.image_from_buffer(
+                decode_packed::<Uv88>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Uvwq8888 => self.image_from_buffer(
+                decode_packed::<Uvwq8888>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Uvlx8888 => self.image_from_buffer(
+                decode_packed::<Uvlx8888>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Rgb888Bluescreen => self.image_from_buffer(
+                decode_packed::<Rgb888Bluescreen>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Bgr888Bluescreen => self.image_from_buffer(
+                decode_packed::<Bgr888Bluescreen>(bytes),
+                width,
+                height,
+                DynamicImage::ImageRgba8,
+            ),
+            ImageFormat::Rgba16161616f => {
+                let buf: Vec<f32> = bytes
+                    .chunks_exact(2)
+                    .map(|c| half_to_f32(u16::from_le_bytes([c[0], c[1]])))
+                    .collect();
+                self.image_from_buffer(buf, width, height, DynamicImage::ImageRgba32F)
+            }
+            ImageFormat::Rgba16161616 => {
+                let buf: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect();
+                self.image_from_buffer(buf, width, height, DynamicImage::ImageRgba16)
             }
             _ => Err(Error::UnsupportedImageFormat(self.format)),
         }
     }
 }
 
+impl<'a> ImageDecoder<'a> for &'a VTFImage<'a> {
+    type Reader = Cursor<Vec<u8>>;
+
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width as u32, self.height as u32)
+    }
+
+    fn color_type(&self) -> ColorType {
+        color_type_for(self.format)
+    }
+
+    fn into_reader(self) -> ImageResult<Self::Reader> {
+        let image = self.decode(0).map_err(|err| {
+            ImageError::Decoding(DecodingError::new(ImageFormatHint::Name("vtf".into()), err))
+        })?;
+        Ok(Cursor::new(image.into_bytes()))
+    }
+}
+
+/// The `image::ColorType` that each `ImageFormat` decodes into; mirrors the buffer types chosen
+/// in [`VTFImage::decode_bytes`].
+fn color_type_for(format: ImageFormat) -> ColorType {
+    match format {
+        ImageFormat::Rgb888 | ImageFormat::Bgr888 => ColorType::Rgb8,
+        ImageFormat::Rgba16161616f => ColorType::Rgba32F,
+        ImageFormat::Rgba16161616 => ColorType::Rgba16,
+        _ => ColorType::Rgba8,
+    }
+}
+
 // https://github.com/image-rs/image/pull/1482#issuecomment-1402362448
 fn convert_bgra(bgra: &mut Vec<u8>) {
     for src in bgra.chunks_exact_mut(4) {
@@ -122,6 +357,290 @@ fn convert_bgra(bgra: &mut Vec<u8>) {
     }
 }
 
+fn swap_red_blue(bytes: &mut [u8], stride: usize) {
+    for pixel in bytes.chunks_exact_mut(stride) {
+        pixel.swap(0, 2);
+    }
+}
+
+/// The number of mipmap levels `width`/`height` can support: one level per halving down to
+/// 1x1, capped at 32 to keep the shift in [`mipmap_dimensions`] always well-defined.
+fn max_mipmap_count(width: u16, height: u16) -> u32 {
+    let largest = (width.max(height) as u32).max(1);
+    (32 - largest.leading_zeros()).min(32)
+}
+
+/// Clamps a mipmap count read from an (untrusted) VTF header to what `width`/`height` can
+/// actually support.
+fn clamp_mipmap_count(header_count: u32, width: u16, height: u16) -> u32 {
+    header_count.min(max_mipmap_count(width, height))
+}
+
+/// Computes the pixel dimensions of `mipmap` for a `width`x`height` base image, halving once per
+/// level down to a minimum of 1x1. The shift overflows for `mipmap >= 32`; callers bound
+/// `mipmap` against [`clamp_mipmap_count`] first, which keeps it well below that.
+fn mipmap_dimensions(width: u16, height: u16, mipmap: u32) -> (u32, u32) {
+    let width = (width as u32 >> mipmap).max(1);
+    let height = (height as u32 >> mipmap).max(1);
+    (width, height)
+}
+
+/// Checks that `mipmap` is a valid level given `mipmap_count` levels are stored, shared by
+/// [`VTFImage::get_mipmap`] and [`VTFImage::decode_mipmap`].
+fn validate_mipmap(mipmap: u32, mipmap_count: u32) -> Result<(), Error> {
+    if mipmap >= mipmap_count {
+        Err(Error::InvalidImageData)
+    } else {
+        Ok(())
+    }
+}
+
+/// A packed pixel format that can be unpacked into RGBA8 one pixel at a time.
+///
+/// Implementors describe how many bytes make up a pixel and how to turn the little-endian
+/// value of those bytes into an `[r, g, b, a]` quadruplet; [`decode_packed`] drives the loop
+/// that walks a frame's bytes and expands every pixel through that mapping.
+trait PackedFormat {
+    const BYTES_PER_PIXEL: usize;
+
+    fn unpack_to_rgba(raw: u32) -> [u8; 4];
+}
+
+fn decode_packed<T: PackedFormat>(bytes: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(bytes.len() / T::BYTES_PER_PIXEL * 4);
+    for chunk in bytes.chunks_exact(T::BYTES_PER_PIXEL) {
+        let mut raw = 0u32;
+        for (i, &byte) in chunk.iter().enumerate() {
+            raw |= (byte as u32) << (8 * i);
+        }
+        output.extend_from_slice(&T::unpack_to_rgba(raw));
+    }
+    output
+}
+
+/// Expands a 5-bit or 6-bit channel to 8 bits by replicating its high bits into the low bits,
+/// so e.g. `0x1f` (max 5-bit value) maps to `0xff` rather than `0xf8`.
+fn expand_bits(value: u32, bits: u32) -> u8 {
+    let value = value << (8 - bits);
+    (value | (value >> bits)) as u8
+}
+
+struct Abgr8888;
+impl PackedFormat for Abgr8888 {
+    const BYTES_PER_PIXEL: usize = 4;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [a, b, g, r] = raw.to_le_bytes();
+        [r, g, b, a]
+    }
+}
+
+struct Argb8888;
+impl PackedFormat for Argb8888 {
+    const BYTES_PER_PIXEL: usize = 4;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [a, r, g, b] = raw.to_le_bytes();
+        [r, g, b, a]
+    }
+}
+
+struct Bgrx8888;
+impl PackedFormat for Bgrx8888 {
+    const BYTES_PER_PIXEL: usize = 4;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [b, g, r, _x] = raw.to_le_bytes();
+        [r, g, b, 255]
+    }
+}
+
+struct Rgb565;
+impl PackedFormat for Rgb565 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let r = expand_bits((raw >> 11) & 0x1f, 5);
+        let g = expand_bits((raw >> 5) & 0x3f, 6);
+        let b = expand_bits(raw & 0x1f, 5);
+        [r, g, b, 255]
+    }
+}
+
+struct Bgr565;
+impl PackedFormat for Bgr565 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let b = expand_bits((raw >> 11) & 0x1f, 5);
+        let g = expand_bits((raw >> 5) & 0x3f, 6);
+        let r = expand_bits(raw & 0x1f, 5);
+        [r, g, b, 255]
+    }
+}
+
+struct Bgrx5551;
+impl PackedFormat for Bgrx5551 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let b = expand_bits((raw >> 10) & 0x1f, 5);
+        let g = expand_bits((raw >> 5) & 0x1f, 5);
+        let r = expand_bits(raw & 0x1f, 5);
+        [r, g, b, 255]
+    }
+}
+
+struct Bgra5551;
+impl PackedFormat for Bgra5551 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let a = if (raw >> 15) & 1 == 1 { 255 } else { 0 };
+        let b = expand_bits((raw >> 10) & 0x1f, 5);
+        let g = expand_bits((raw >> 5) & 0x1f, 5);
+        let r = expand_bits(raw & 0x1f, 5);
+        [r, g, b, a]
+    }
+}
+
+struct Bgra4444;
+impl PackedFormat for Bgra4444 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let b = ((raw & 0xf) * 0x11) as u8;
+        let g = (((raw >> 4) & 0xf) * 0x11) as u8;
+        let r = (((raw >> 8) & 0xf) * 0x11) as u8;
+        let a = (((raw >> 12) & 0xf) * 0x11) as u8;
+        [r, g, b, a]
+    }
+}
+
+struct I8;
+impl PackedFormat for I8 {
+    const BYTES_PER_PIXEL: usize = 1;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let i = raw as u8;
+        [i, i, i, 255]
+    }
+}
+
+struct Ia88;
+impl PackedFormat for Ia88 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let i = (raw & 0xff) as u8;
+        let a = ((raw >> 8) & 0xff) as u8;
+        [i, i, i, a]
+    }
+}
+
+struct A8;
+impl PackedFormat for A8 {
+    const BYTES_PER_PIXEL: usize = 1;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        [255, 255, 255, raw as u8]
+    }
+}
+
+/// VTF's 8-bit paletted format. The palette itself isn't carried by the image payload, so the
+/// index is decoded as grayscale intensity rather than looked up against a color table.
+struct P8;
+impl PackedFormat for P8 {
+    const BYTES_PER_PIXEL: usize = 1;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let i = raw as u8;
+        [i, i, i, 255]
+    }
+}
+
+/// Maps a signed 8-bit bump-map channel onto an unsigned 8-bit color channel.
+fn unpack_signed(byte: u8) -> u8 {
+    (byte as i8 as i32 + 128) as u8
+}
+
+struct Uv88;
+impl PackedFormat for Uv88 {
+    const BYTES_PER_PIXEL: usize = 2;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let u = unpack_signed((raw & 0xff) as u8);
+        let v = unpack_signed(((raw >> 8) & 0xff) as u8);
+        [u, v, 0, 255]
+    }
+}
+
+struct Uvwq8888;
+impl PackedFormat for Uvwq8888 {
+    const BYTES_PER_PIXEL: usize = 4;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [u, v, w, q] = raw.to_le_bytes();
+        [
+            unpack_signed(u),
+            unpack_signed(v),
+            unpack_signed(w),
+            unpack_signed(q),
+        ]
+    }
+}
+
+struct Uvlx8888;
+impl PackedFormat for Uvlx8888 {
+    const BYTES_PER_PIXEL: usize = 4;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [u, v, l, _x] = raw.to_le_bytes();
+        [unpack_signed(u), unpack_signed(v), l, 255]
+    }
+}
+
+/// The Source engine "bluescreen" key color: pixels matching it are fully transparent.
+const BLUESCREEN_KEY: [u8; 3] = [0, 0, 255];
+
+struct Rgb888Bluescreen;
+impl PackedFormat for Rgb888Bluescreen {
+    const BYTES_PER_PIXEL: usize = 3;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [r, g, b, _] = raw.to_le_bytes();
+        let alpha = if [r, g, b] == BLUESCREEN_KEY { 0 } else { 255 };
+        [r, g, b, alpha]
+    }
+}
+
+/// Converts an IEEE 754 half-precision float (as raw 16 bits) to `f32`, handling subnormals and
+/// re-biasing the exponent from half's 15 to f32's 127.
+fn half_to_f32(half: u16) -> f32 {
+    let sign = (half >> 15) & 0x1;
+    let exponent = (half >> 10) & 0x1f;
+    let mantissa = half & 0x3ff;
+
+    let bits = if exponent == 0 {
+        if mantissa == 0 {
+            (sign as u32) << 31
+        } else {
+            // Subnormal half: normalize the mantissa by shifting it until the implicit
+            // leading bit appears, adjusting the exponent to match.
+            let mut mantissa = mantissa as u32;
+            let mut exponent = 1i32;
+            while mantissa & 0x400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            mantissa &= 0x3ff;
+            let exponent = (exponent - 15 + 127) as u32;
+            ((sign as u32) << 31) | (exponent << 23) | (mantissa << 13)
+        }
+    } else if exponent == 0x1f {
+        ((sign as u32) << 31) | (0xff << 23) | ((mantissa as u32) << 13)
+    } else {
+        let exponent = exponent as u32 - 15 + 127;
+        ((sign as u32) << 31) | (exponent << 23) | ((mantissa as u32) << 13)
+    };
+
+    f32::from_bits(bits)
+}
+
+struct Bgr888Bluescreen;
+impl PackedFormat for Bgr888Bluescreen {
+    const BYTES_PER_PIXEL: usize = 3;
+    fn unpack_to_rgba(raw: u32) -> [u8; 4] {
+        let [b, g, r, _] = raw.to_le_bytes();
+        let alpha = if [r, g, b] == BLUESCREEN_KEY { 0 } else { 255 };
+        [r, g, b, alpha]
+    }
+}
+
 #[derive(Debug, Display, Clone, Copy, PartialEq, TryFromPrimitive)]
 #[repr(i16)]
 pub enum ImageFormat {
@@ -169,11 +688,146 @@ impl ImageFormat {
             ImageFormat::A8 => Ok(width * height),
             ImageFormat::Argb8888 => Ok(width * height * 4),
             ImageFormat::Bgra8888 => Ok(width * height * 4),
+            ImageFormat::Rgb888Bluescreen => Ok(width * height * 3),
+            ImageFormat::Bgr888Bluescreen => Ok(width * height * 3),
+            ImageFormat::Bgrx8888 => Ok(width * height * 4),
+            ImageFormat::Bgr565 => Ok(width * height * 2),
+            ImageFormat::Bgrx5551 => Ok(width * height * 2),
+            ImageFormat::Bgra4444 => Ok(width * height * 2),
+            ImageFormat::Bgra5551 => Ok(width * height * 2),
+            ImageFormat::Uv88 => Ok(width * height * 2),
+            ImageFormat::Uvwq8888 => Ok(width * height * 4),
+            ImageFormat::Uvlx8888 => Ok(width * height * 4),
+            ImageFormat::P8 => Ok(width * height),
             ImageFormat::Dxt1 => Ok(((width + 3) / 4) * ((height + 3) / 4) * 8),
+            ImageFormat::Dxt1Onebitalpha => Ok(((width + 3) / 4) * ((height + 3) / 4) * 8),
+            ImageFormat::Dxt3 => Ok(((width + 3) / 4) * ((height + 3) / 4) * 16),
             ImageFormat::Dxt5 => Ok(((width + 3) / 4) * ((height + 3) / 4) * 16),
             ImageFormat::Rgba16161616f => Ok(width * height * 8),
             ImageFormat::Rgba16161616 => Ok(width * height * 8),
-            _ => Err(Error::UnsupportedImageFormat(*self)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_to_f32_zero() {
+        assert_eq!(half_to_f32(0x0000), 0.0);
+        assert!(half_to_f32(0x8000).is_sign_negative());
+        assert_eq!(half_to_f32(0x8000), -0.0);
+    }
+
+    #[test]
+    fn half_to_f32_normals() {
+        assert_eq!(half_to_f32(0x3c00), 1.0);
+        assert_eq!(half_to_f32(0xc000), -2.0);
+        assert_eq!(half_to_f32(0x7bff), 65504.0);
+    }
+
+    #[test]
+    fn half_to_f32_subnormal() {
+        // Smallest positive subnormal half, 2^-24.
+        assert_eq!(half_to_f32(0x0001), 5.9604645e-8);
+    }
+
+    #[test]
+    fn half_to_f32_infinity_and_nan() {
+        assert_eq!(half_to_f32(0x7c00), f32::INFINITY);
+        assert_eq!(half_to_f32(0xfc00), f32::NEG_INFINITY);
+        assert!(half_to_f32(0x7e00).is_nan());
+    }
+
+    #[test]
+    fn rgb565_unpacks_white() {
+        assert_eq!(Rgb565::unpack_to_rgba(0xffff), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn bgra4444_unpacks_channels() {
+        // B=0x1, G=0x2, R=0x3, A=0x4 (nibbles low-to-high), scaled by 0x11.
+        assert_eq!(Bgra4444::unpack_to_rgba(0x4321), [0x33, 0x22, 0x11, 0x44]);
+    }
+
+    #[test]
+    fn a8_unpacks_alpha_only() {
+        assert_eq!(A8::unpack_to_rgba(0x80), [255, 255, 255, 0x80]);
+    }
+
+    #[test]
+    fn uv88_unpacks_signed_channels() {
+        // u byte 0xff (-1 signed) -> 127, v byte 0x00 -> 128.
+        assert_eq!(Uv88::unpack_to_rgba(0x00ff), [127, 128, 0, 255]);
+    }
+
+    #[test]
+    fn encode_rgba8888_roundtrips() {
+        let image = DynamicImage::ImageRgba8(ImageBuffer::from_fn(2, 2, |x, y| {
+            image::Rgba([x as u8 * 10, y as u8 * 10, 1, 255])
+        }));
+        let encoded = VTFImage::encode(&image, ImageFormat::Rgba8888).unwrap();
+        assert_eq!(encoded, image.to_rgba8().into_raw());
+    }
+
+    #[test]
+    fn encode_bgr888_swaps_red_and_blue() {
+        let image = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(1, 1, image::Rgb([1, 2, 3])));
+        let encoded = VTFImage::encode(&image, ImageFormat::Bgr888).unwrap();
+        assert_eq!(encoded, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn mipmap_dimensions_halves_down_to_one() {
+        assert_eq!(mipmap_dimensions(8, 4, 0), (8, 4));
+        assert_eq!(mipmap_dimensions(8, 4, 1), (4, 2));
+        assert_eq!(mipmap_dimensions(8, 4, 2), (2, 1));
+        assert_eq!(mipmap_dimensions(8, 4, 3), (1, 1));
+        // Past the point both dimensions hit 1, they stay clamped at 1 rather than going to 0.
+        assert_eq!(mipmap_dimensions(8, 4, 10), (1, 1));
+    }
+
+    #[test]
+    fn max_mipmap_count_matches_dimensions() {
+        assert_eq!(max_mipmap_count(1, 1), 1);
+        assert_eq!(max_mipmap_count(8, 4), 4);
+        assert_eq!(max_mipmap_count(256, 1), 9);
+    }
+
+    #[test]
+    fn clamp_mipmap_count_rejects_header_overclaim() {
+        // A corrupted/malicious header claiming 255 levels for a tiny image must be clamped
+        // down to what the dimensions can support, not trusted outright (bfeb122/16de8ea).
+        assert_eq!(clamp_mipmap_count(255, 8, 4), 4);
+        assert_eq!(clamp_mipmap_count(2, 8, 4), 2);
+    }
+
+    #[test]
+    fn validate_mipmap_rejects_out_of_range_levels() {
+        assert!(validate_mipmap(2, 3).is_ok());
+        assert!(matches!(
+            validate_mipmap(3, 3),
+            Err(Error::InvalidImageData)
+        ));
+        assert!(matches!(
+            validate_mipmap(32, 3),
+            Err(Error::InvalidImageData)
+        ));
+    }
+
+    #[test]
+    fn color_type_for_matches_decode_bytes_buffers() {
+        assert_eq!(color_type_for(ImageFormat::Rgb888), ColorType::Rgb8);
+        assert_eq!(color_type_for(ImageFormat::Bgr888), ColorType::Rgb8);
+        assert_eq!(color_type_for(ImageFormat::Rgba8888), ColorType::Rgba8);
+        assert_eq!(
+            color_type_for(ImageFormat::Rgba16161616f),
+            ColorType::Rgba32F
+        );
+        assert_eq!(
+            color_type_for(ImageFormat::Rgba16161616),
+            ColorType::Rgba16
+        );
+    }
+}